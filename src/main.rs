@@ -9,11 +9,93 @@ const NORMAL_BUTTON: Color = Color::rgb(0.15, 0.15, 0.35);
 const HOVERED_BUTTON: Color = Color::rgb(0.25, 0.25, 0.45);
 const PRESSED_BUTTON: Color = Color::rgb(0.35, 0.75, 0.55);
 
+const DIGIT_WIDTH: f32 = 20.0;
+const DIGIT_HEIGHT: f32 = 34.0;
+const SEGMENT_THICKNESS: f32 = 4.0;
+const SEGMENT_ON: Color = Color::rgb(0.9, 0.15, 0.15);
+const SEGMENT_OFF: Color = Color::rgba(0.25, 0.05, 0.05, 0.4);
+
+// Segment order is a, b, c, d, e, f, g (clockwise from the top, g is the middle bar).
+const DIGIT_SEGMENTS: [[bool; 7]; 10] = [
+    [true, true, true, true, true, true, false],
+    [false, true, true, false, false, false, false],
+    [true, true, false, true, true, false, true],
+    [true, true, true, true, false, false, true],
+    [false, true, true, false, false, true, true],
+    [true, false, true, true, false, true, true],
+    [true, false, true, true, true, true, true],
+    [true, true, true, false, false, false, false],
+    [true, true, true, true, true, true, true],
+    [true, true, true, true, false, true, true],
+];
+
+fn segment_style(segment: usize) -> Style {
+    let (left, top, width, height) = match segment {
+        0 => (
+            SEGMENT_THICKNESS,
+            0.0,
+            DIGIT_WIDTH - 2.0 * SEGMENT_THICKNESS,
+            SEGMENT_THICKNESS,
+        ),
+        1 => (
+            DIGIT_WIDTH - SEGMENT_THICKNESS,
+            SEGMENT_THICKNESS,
+            SEGMENT_THICKNESS,
+            DIGIT_HEIGHT / 2.0 - SEGMENT_THICKNESS,
+        ),
+        2 => (
+            DIGIT_WIDTH - SEGMENT_THICKNESS,
+            DIGIT_HEIGHT / 2.0,
+            SEGMENT_THICKNESS,
+            DIGIT_HEIGHT / 2.0 - SEGMENT_THICKNESS,
+        ),
+        3 => (
+            SEGMENT_THICKNESS,
+            DIGIT_HEIGHT - SEGMENT_THICKNESS,
+            DIGIT_WIDTH - 2.0 * SEGMENT_THICKNESS,
+            SEGMENT_THICKNESS,
+        ),
+        4 => (
+            0.0,
+            DIGIT_HEIGHT / 2.0,
+            SEGMENT_THICKNESS,
+            DIGIT_HEIGHT / 2.0 - SEGMENT_THICKNESS,
+        ),
+        5 => (
+            0.0,
+            SEGMENT_THICKNESS,
+            SEGMENT_THICKNESS,
+            DIGIT_HEIGHT / 2.0 - SEGMENT_THICKNESS,
+        ),
+        6 => (
+            SEGMENT_THICKNESS,
+            DIGIT_HEIGHT / 2.0 - SEGMENT_THICKNESS / 2.0,
+            DIGIT_WIDTH - 2.0 * SEGMENT_THICKNESS,
+            SEGMENT_THICKNESS,
+        ),
+        _ => unreachable!("a seven-segment digit only has 7 segments"),
+    };
+    Style {
+        position_type: PositionType::Absolute,
+        position: Rect {
+            left: Val::Px(left),
+            top: Val::Px(top),
+            ..Default::default()
+        },
+        size: Size {
+            width: Val::Px(width),
+            height: Val::Px(height),
+        },
+        ..Default::default()
+    }
+}
+
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 enum GameState {
     Menu,
     Playing,
     GameOver,
+    Settings,
 }
 
 struct GameText(TextStyle);
@@ -82,10 +164,296 @@ impl Tile {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Difficulty {
+    Easy,
+    Hard,
+}
+
+impl Default for Difficulty {
+    fn default() -> Self {
+        Difficulty::Hard
+    }
+}
+
+impl Difficulty {
+    fn next(self) -> Self {
+        match self {
+            Difficulty::Easy => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::Easy,
+        }
+    }
+
+    fn label(self, language: &Language) -> String {
+        let key = match self {
+            Difficulty::Easy => TextKey::DifficultyEasy,
+            Difficulty::Hard => TextKey::DifficultyHard,
+        };
+        language.localized(key).to_owned()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FirstMove {
+    Player,
+    Computer,
+}
+
+impl FirstMove {
+    fn next(self) -> Self {
+        match self {
+            FirstMove::Player => FirstMove::Computer,
+            FirstMove::Computer => FirstMove::Player,
+        }
+    }
+
+    fn label(self, language: &Language) -> String {
+        let key = match self {
+            FirstMove::Player => TextKey::FirstMovePlayer,
+            FirstMove::Computer => TextKey::FirstMoveComputer,
+        };
+        language.localized(key).to_owned()
+    }
+}
+
+const MATCH_LENGTHS: [u32; 4] = [1, 3, 5, 7];
+
+struct Settings {
+    difficulty: Difficulty,
+    first_move: FirstMove,
+    match_length: u32,
+}
+
+impl Settings {
+    fn next_match_length(&mut self) {
+        let i = MATCH_LENGTHS
+            .iter()
+            .position(|&n| n == self.match_length)
+            .unwrap_or(0);
+        self.match_length = MATCH_LENGTHS[(i + 1) % MATCH_LENGTHS.len()];
+    }
+
+    fn match_length_label(&self, language: &Language) -> String {
+        language
+            .localized(TextKey::MatchLengthFormat)
+            .replace("{}", &self.match_length.to_string())
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            difficulty: Difficulty::default(),
+            first_move: FirstMove::Player,
+            match_length: MATCH_LENGTHS[0],
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Language {
+    English,
+    French,
+}
+
+impl Default for Language {
+    fn default() -> Self {
+        Language::English
+    }
+}
+
+impl Language {
+    fn next(self) -> Self {
+        match self {
+            Language::English => Language::French,
+            Language::French => Language::English,
+        }
+    }
+
+    fn label(self) -> String {
+        let key = match self {
+            Language::English => TextKey::LanguageEnglish,
+            Language::French => TextKey::LanguageFrench,
+        };
+        self.localized(key).to_owned()
+    }
+
+    fn localized(&self, key: TextKey) -> &'static str {
+        match (self, key) {
+            (Language::English, TextKey::Title) => "noughts and crosses",
+            (Language::English, TextKey::Play) => "play",
+            (Language::English, TextKey::Settings) => "settings",
+            (Language::English, TextKey::Quit) => "quit",
+            (Language::English, TextKey::Back) => "back",
+            (Language::English, TextKey::Board) => "board",
+            (Language::English, TextKey::GameOver) => "game over",
+            (Language::English, TextKey::PlayAgain) => "play again",
+            (Language::English, TextKey::Paused) => "paused",
+            (Language::English, TextKey::Resume) => "resume",
+            (Language::English, TextKey::QuitToMenu) => "quit to menu",
+            (Language::English, TextKey::You) => "you",
+            (Language::English, TextKey::Cpu) => "cpu",
+            (Language::English, TextKey::Draws) => "draws",
+            (Language::English, TextKey::PlayerWins) => "you win!",
+            (Language::English, TextKey::ComputerWins) => "computer wins!",
+            (Language::English, TextKey::Draw) => "draw",
+            (Language::English, TextKey::DifficultyEasy) => "difficulty: easy",
+            (Language::English, TextKey::DifficultyHard) => "difficulty: hard",
+            (Language::English, TextKey::FirstMovePlayer) => "first move: player",
+            (Language::English, TextKey::FirstMoveComputer) => "first move: computer",
+            (Language::English, TextKey::MatchLengthFormat) => "first to {}",
+            (Language::English, TextKey::LanguageEnglish) => "language: english",
+            (Language::English, TextKey::LanguageFrench) => "language: french",
+            (Language::French, TextKey::Title) => "morpion",
+            (Language::French, TextKey::Play) => "jouer",
+            (Language::French, TextKey::Settings) => "options",
+            (Language::French, TextKey::Quit) => "quitter",
+            (Language::French, TextKey::Back) => "retour",
+            (Language::French, TextKey::Board) => "plateau",
+            (Language::French, TextKey::GameOver) => "partie terminée",
+            (Language::French, TextKey::PlayAgain) => "rejouer",
+            (Language::French, TextKey::Paused) => "en pause",
+            (Language::French, TextKey::Resume) => "reprendre",
+            (Language::French, TextKey::QuitToMenu) => "quitter au menu",
+            (Language::French, TextKey::You) => "vous",
+            (Language::French, TextKey::Cpu) => "ordi",
+            (Language::French, TextKey::Draws) => "nuls",
+            (Language::French, TextKey::PlayerWins) => "vous gagnez !",
+            (Language::French, TextKey::ComputerWins) => "l'ordinateur gagne !",
+            (Language::French, TextKey::Draw) => "match nul",
+            (Language::French, TextKey::DifficultyEasy) => "difficulté : facile",
+            (Language::French, TextKey::DifficultyHard) => "difficulté : difficile",
+            (Language::French, TextKey::FirstMovePlayer) => "premier coup : joueur",
+            (Language::French, TextKey::FirstMoveComputer) => "premier coup : ordinateur",
+            (Language::French, TextKey::MatchLengthFormat) => "première à {}",
+            (Language::French, TextKey::LanguageEnglish) => "langue : anglais",
+            (Language::French, TextKey::LanguageFrench) => "langue : français",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TextKey {
+    Title,
+    Play,
+    Settings,
+    Quit,
+    Back,
+    Board,
+    GameOver,
+    PlayAgain,
+    Paused,
+    Resume,
+    QuitToMenu,
+    You,
+    Cpu,
+    Draws,
+    PlayerWins,
+    ComputerWins,
+    Draw,
+    DifficultyEasy,
+    DifficultyHard,
+    FirstMovePlayer,
+    FirstMoveComputer,
+    MatchLengthFormat,
+    LanguageEnglish,
+    LanguageFrench,
+}
+
+// Tags a text entity spawned from `Language::localized` so `retranslate` can
+// find and rewrite it when the language changes.
+#[derive(Component)]
+struct Localized(TextKey);
+
+fn opponent(side: Tile) -> Tile {
+    match side {
+        Tile::X => Tile::O,
+        Tile::O => Tile::X,
+        Tile::Empty => Tile::Empty,
+    }
+}
+
+const LINES: [[usize; 3]; 8] = [
+    [0, 1, 2],
+    [3, 4, 5],
+    [6, 7, 8],
+    [0, 3, 6],
+    [1, 4, 7],
+    [2, 5, 8],
+    [0, 4, 8],
+    [2, 4, 6],
+];
+
+fn winner_of(tiles: &[Tile; 9]) -> Option<(Tile, [usize; 3])> {
+    for player in [Tile::O, Tile::X] {
+        for line in LINES {
+            if line.iter().all(|&i| tiles[i] == player) {
+                return Some((player, line));
+            }
+        }
+    }
+    None
+}
+
+#[derive(Clone, Copy)]
+enum Outcome {
+    Ongoing,
+    Win(Tile, [usize; 3]),
+    Draw,
+}
+
+fn square_color(index: usize) -> Color {
+    let x = index % 3;
+    let y = index / 3;
+    if (x + y) % 2 == 0 {
+        Color::MAROON
+    } else {
+        Color::BLACK
+    }
+}
+
+// Minimax: `side` is the player to move at this node, X maximizes
+// and O minimizes. Alpha-beta pruning just trims branches that can't change the
+// parent's choice, it doesn't change the result.
+fn minimax(tiles: &mut [Tile; 9], depth: i32, side: Tile, mut alpha: i32, mut beta: i32) -> i32 {
+    if let Some((winner, _)) = winner_of(tiles) {
+        return match winner {
+            Tile::X => 10 - depth,
+            Tile::O => depth - 10,
+            Tile::Empty => 0,
+        };
+    }
+    if tiles.iter().all(|t| !t.is_empty()) {
+        return 0;
+    }
+    let maximizing = side == Tile::X;
+    let mut best = if maximizing { i32::MIN } else { i32::MAX };
+    for index in 0..9 {
+        if !tiles[index].is_empty() {
+            continue;
+        }
+        tiles[index] = side;
+        let score = minimax(tiles, depth + 1, opponent(side), alpha, beta);
+        tiles[index] = Tile::Empty;
+        if maximizing {
+            best = best.max(score);
+            alpha = alpha.max(best);
+        } else {
+            best = best.min(score);
+            beta = beta.min(best);
+        }
+        if beta <= alpha {
+            break;
+        }
+    }
+    best
+}
+
 struct Board {
     moves: u8,
     tiles: [Tile; 9],
     entities: [Entity; 9],
+    squares: [Entity; 9],
 }
 
 impl Board {
@@ -96,46 +464,66 @@ impl Board {
         }
     }
 
-    fn play_move(&mut self, index: usize) -> bool {
-        if self.tiles[index].is_empty() {
-            self.tiles[index] = Tile::O;
-            if self.winning() {
-                return true;
-            };
-            self.moves += 1;
-            if self.moves < 8 {
-                self.moves += 1;
+    fn play_move(&mut self, index: usize, difficulty: Difficulty) -> Outcome {
+        if !self.tiles[index].is_empty() {
+            return Outcome::Ongoing;
+        }
+        self.tiles[index] = Tile::O;
+        self.moves += 1;
+        if let Some((winner, line)) = self.winner() {
+            return Outcome::Win(winner, line);
+        }
+        if self.moves == 9 {
+            return Outcome::Draw;
+        }
+        let ai_index = self.choose_ai_move(difficulty);
+        self.tiles[ai_index] = Tile::X;
+        self.moves += 1;
+        if let Some((winner, line)) = self.winner() {
+            return Outcome::Win(winner, line);
+        }
+        if self.moves == 9 {
+            return Outcome::Draw;
+        }
+        Outcome::Ongoing
+    }
+
+    fn winner(&self) -> Option<(Tile, [usize; 3])> {
+        winner_of(&self.tiles)
+    }
+
+    /// Picks `X`'s next move according to `difficulty`, without playing it.
+    fn choose_ai_move(&self, difficulty: Difficulty) -> usize {
+        match difficulty {
+            Difficulty::Easy => {
                 let rng = &mut thread_rng();
                 let mut possible_moves: Vec<usize> =
                     (0..=8usize).filter(|i| self.tiles[*i].is_empty()).collect();
                 possible_moves.shuffle(rng);
-                self.tiles[possible_moves[0]] = Tile::X;
-                if self.winning() {
-                    return true;
-                };
+                possible_moves[0]
             }
+            Difficulty::Hard => self.best_move(),
         }
-        self.moves == 9
     }
 
-    fn winning(&self) -> bool {
-        for player in [Tile::O, Tile::X] {
-            for i in 0..3 {
-                if [0, 3, 6].into_iter().all(|j| self.tiles[i + j] == player)
-                    || [0, 1, 2]
-                        .into_iter()
-                        .all(|j| self.tiles[i * 3 + j] == player)
-                {
-                    return true;
-                }
+    /// Picks the optimal move for `X` (the computer) via minimax search.
+    fn best_move(&self) -> usize {
+        let mut best_score = i32::MIN;
+        let mut best_index = self.tiles.iter().position(|t| t.is_empty()).unwrap_or(0);
+        let mut tiles = self.tiles;
+        for index in 0..9 {
+            if !tiles[index].is_empty() {
+                continue;
             }
-            if [0, 4, 8].into_iter().all(|i| self.tiles[i] == player)
-                || [2, 4, 6].into_iter().all(|i| self.tiles[i] == player)
-            {
-                return true;
+            tiles[index] = Tile::X;
+            let score = minimax(&mut tiles, 1, Tile::O, i32::MIN, i32::MAX);
+            tiles[index] = Tile::Empty;
+            if score > best_score {
+                best_score = score;
+                best_index = index;
             }
         }
-        false
+        best_index
     }
 }
 
@@ -144,6 +532,7 @@ impl FromWorld for Board {
         Self {
             tiles: [Tile::Empty; 9],
             entities: [(); 9].map(|_| world.spawn().id()),
+            squares: [(); 9].map(|_| world.spawn().id()),
             moves: 0,
         }
     }
@@ -161,9 +550,76 @@ impl FromWorld for GameText {
     }
 }
 
+// Modeled as a plain resource rather than a real Bevy `SubStates` (as the
+// sub_states example does it), since that derive postdates this Bevy version.
+// `toggle_pause` restricts the flag to `GameState::Playing`, giving the same
+// "substate only exists under a parent state" behavior by hand.
+#[derive(Default)]
+struct IsPaused(bool);
+
+#[derive(Default)]
+struct WinningLine(Option<[usize; 3]>);
+
+const WIN_HIGHLIGHT: Color = Color::rgb(0.95, 0.85, 0.25);
+
+struct GameOverUi {
+    message: Entity,
+}
+
+impl FromWorld for GameOverUi {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            message: world.spawn().id(),
+        }
+    }
+}
+
+// Each tally is rendered as a single seven-segment digit, so it's capped here
+// rather than silently wrapping past 9 (`draws` in particular has no other
+// reset point and can otherwise climb past a round-length in a long session).
+const MAX_TALLY: u32 = 9;
+
+#[derive(Default)]
+struct Score {
+    player: u32,
+    computer: u32,
+    draws: u32,
+}
+
+struct ScoreDisplay {
+    player: [Entity; 7],
+    computer: [Entity; 7],
+    draws: [Entity; 7],
+}
+
+impl ScoreDisplay {
+    fn spawn(world: &mut World) -> Self {
+        Self {
+            player: [(); 7].map(|_| world.spawn().id()),
+            computer: [(); 7].map(|_| world.spawn().id()),
+            draws: [(); 7].map(|_| world.spawn().id()),
+        }
+    }
+}
+
+struct ScoreUi {
+    board: ScoreDisplay,
+    game_over: ScoreDisplay,
+}
+
+impl FromWorld for ScoreUi {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            board: ScoreDisplay::spawn(world),
+            game_over: ScoreDisplay::spawn(world),
+        }
+    }
+}
+
 struct UiNodes {
     root: Entity,
-    states: [Entity; 3],
+    states: [Entity; 4],
+    pause: Entity,
 }
 
 impl UiNodes {
@@ -176,13 +632,35 @@ impl UiNodes {
     fn game_over(&self) -> Entity {
         self.states[GameState::GameOver as usize]
     }
+    fn settings(&self) -> Entity {
+        self.states[GameState::Settings as usize]
+    }
 }
 
 impl FromWorld for UiNodes {
     fn from_world(world: &mut World) -> Self {
         Self {
             root: world.spawn().id(),
-            states: [(); 3].map(|_| world.spawn().id()),
+            states: [(); 4].map(|_| world.spawn().id()),
+            pause: world.spawn().id(),
+        }
+    }
+}
+
+struct SettingsUi {
+    difficulty_label: Entity,
+    first_move_label: Entity,
+    match_length_label: Entity,
+    language_label: Entity,
+}
+
+impl FromWorld for SettingsUi {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            difficulty_label: world.spawn().id(),
+            first_move_label: world.spawn().id(),
+            match_length_label: world.spawn().id(),
+            language_label: world.spawn().id(),
         }
     }
 }
@@ -208,10 +686,15 @@ fn make_ui_root(mut commands: Commands, ui_nodes: Res<UiNodes>) {
             },
             ..Default::default()
         })
-        .push_children(&ui_nodes.states[..2]);
+        .push_children(&[ui_nodes.menu(), ui_nodes.board(), ui_nodes.settings()]);
 }
 
-fn make_menu(mut commands: Commands, text: Res<GameText>, ui_nodes: Res<UiNodes>) {
+fn make_menu(
+    mut commands: Commands,
+    text: Res<GameText>,
+    ui_nodes: Res<UiNodes>,
+    language: Res<Language>,
+) {
     let menu_node = commands
         .entity(ui_nodes.menu())
         .insert_bundle(NodeBundle {
@@ -235,28 +718,201 @@ fn make_menu(mut commands: Commands, text: Res<GameText>, ui_nodes: Res<UiNodes>
                 margin: Rect::all(Val::Px(10.0)),
                 ..Default::default()
             },
-            ..text.bundle("noughts and crosses")
+            ..text.bundle(language.localized(TextKey::Title))
         })
+        .insert(Localized(TextKey::Title))
         .id();
-    let [play_button, quit_button] = [("play", ButtonCommand::Play), ("quit", ButtonCommand::Quit)]
-        .map(|(label, button_command)| {
-            let button_label = commands.spawn_bundle(text.bundle(label)).id();
-            commands
-                .spawn_bundle(ButtonBundle {
-                    style: Style {
-                        margin: Rect::all(Val::Px(10.0)),
-                        padding: Rect::all(Val::Px(10.0)),
-                        ..Default::default()
-                    },
+    let [play_button, settings_button, quit_button] = [
+        (TextKey::Play, ButtonCommand::Play),
+        (TextKey::Settings, ButtonCommand::Settings),
+        (TextKey::Quit, ButtonCommand::Quit),
+    ]
+    .map(|(key, button_command)| {
+        let button_label = commands
+            .spawn_bundle(text.bundle(language.localized(key)))
+            .insert(Localized(key))
+            .id();
+        commands
+            .spawn_bundle(ButtonBundle {
+                style: Style {
+                    margin: Rect::all(Val::Px(10.0)),
+                    padding: Rect::all(Val::Px(10.0)),
                     ..Default::default()
-                })
-                .insert(button_command)
-                .push_children(&[button_label])
-                .id()
-        });
+                },
+                ..Default::default()
+            })
+            .insert(button_command)
+            .push_children(&[button_label])
+            .id()
+    });
     commands
         .entity(menu_node)
-        .push_children(&[title, play_button, quit_button]);
+        .push_children(&[title, play_button, settings_button, quit_button]);
+}
+
+fn make_settings(
+    mut commands: Commands,
+    text: Res<GameText>,
+    ui_nodes: Res<UiNodes>,
+    settings: Res<Settings>,
+    settings_ui: Res<SettingsUi>,
+    language: Res<Language>,
+) {
+    let settings_node = commands
+        .entity(ui_nodes.settings())
+        .insert_bundle(NodeBundle {
+            color: UiColor(Color::DARK_GRAY),
+            style: Style {
+                size: Size {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                },
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::ColumnReverse,
+                justify_content: JustifyContent::Center,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .id();
+    let title = commands
+        .spawn_bundle(TextBundle {
+            style: Style {
+                margin: Rect::all(Val::Px(10.0)),
+                ..Default::default()
+            },
+            ..text.bundle(language.localized(TextKey::Settings))
+        })
+        .insert(Localized(TextKey::Settings))
+        .id();
+    let rows = [
+        (
+            settings_ui.difficulty_label,
+            settings.difficulty.label(&language),
+            ButtonCommand::CycleDifficulty,
+        ),
+        (
+            settings_ui.first_move_label,
+            settings.first_move.label(&language),
+            ButtonCommand::CycleFirstMove,
+        ),
+        (
+            settings_ui.match_length_label,
+            settings.match_length_label(&language),
+            ButtonCommand::CycleMatchLength,
+        ),
+        (
+            settings_ui.language_label,
+            language.label(),
+            ButtonCommand::CycleLanguage,
+        ),
+    ]
+    .map(|(label_entity, label, button_command)| {
+        commands
+            .entity(label_entity)
+            .insert_bundle(text.bundle(&label));
+        commands
+            .spawn_bundle(ButtonBundle {
+                style: Style {
+                    margin: Rect::all(Val::Px(10.0)),
+                    padding: Rect::all(Val::Px(10.0)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .insert(button_command)
+            .push_children(&[label_entity])
+            .id()
+    });
+    let back_label = commands
+        .spawn_bundle(text.bundle(language.localized(TextKey::Back)))
+        .insert(Localized(TextKey::Back))
+        .id();
+    let back_button = commands
+        .spawn_bundle(ButtonBundle {
+            style: Style {
+                margin: Rect::all(Val::Px(10.0)),
+                padding: Rect::all(Val::Px(10.0)),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .insert(ButtonCommand::Back)
+        .push_children(&[back_label])
+        .id();
+    commands
+        .entity(settings_node)
+        .push_children(&[title])
+        .push_children(&rows)
+        .push_children(&[back_button]);
+}
+
+fn make_pause_overlay(
+    mut commands: Commands,
+    ui_nodes: Res<UiNodes>,
+    text: Res<GameText>,
+    language: Res<Language>,
+) {
+    let overlay = commands
+        .entity(ui_nodes.pause)
+        .insert_bundle(NodeBundle {
+            color: UiColor(Color::rgba(0.0, 0.0, 0.0, 0.75)),
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: Rect {
+                    left: Val::Px(0.0),
+                    top: Val::Px(0.0),
+                    ..Default::default()
+                },
+                size: Size {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                },
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::ColumnReverse,
+                justify_content: JustifyContent::Center,
+                display: Display::None,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .id();
+    let title = commands
+        .spawn_bundle(TextBundle {
+            style: Style {
+                margin: Rect::all(Val::Px(10.0)),
+                ..Default::default()
+            },
+            ..text.bundle(language.localized(TextKey::Paused))
+        })
+        .insert(Localized(TextKey::Paused))
+        .id();
+    let [resume_button, quit_button] = [
+        (TextKey::Resume, ButtonCommand::Resume),
+        (TextKey::QuitToMenu, ButtonCommand::QuitToMenu),
+    ]
+    .map(|(key, button_command)| {
+        let button_label = commands
+            .spawn_bundle(text.bundle(language.localized(key)))
+            .insert(Localized(key))
+            .id();
+        commands
+            .spawn_bundle(ButtonBundle {
+                style: Style {
+                    margin: Rect::all(Val::Px(10.0)),
+                    padding: Rect::all(Val::Px(10.0)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .insert(button_command)
+            .push_children(&[button_label])
+            .id()
+    });
+    commands
+        .entity(overlay)
+        .push_children(&[title, resume_button, quit_button]);
+    commands.entity(ui_nodes.board()).push_children(&[overlay]);
 }
 
 fn make_board(
@@ -264,6 +920,7 @@ fn make_board(
     ui_nodes: Res<UiNodes>,
     text: Res<GameText>,
     board: Res<Board>,
+    language: Res<Language>,
 ) {
     commands.entity(ui_nodes.board()).insert_bundle(NodeBundle {
         color: UiColor(Color::DARK_GRAY),
@@ -337,8 +994,9 @@ fn make_board(
                 margin: Rect::all(Val::Px(10.0)),
                 ..Default::default()
             },
-            ..text.bundle("board")
+            ..text.bundle(language.localized(TextKey::Board))
         })
+        .insert(Localized(TextKey::Board))
         .id();
     let grid = commands
         .spawn_bundle(NodeBundle {
@@ -377,13 +1035,10 @@ fn make_board(
                 .entity(board.entities[index])
                 .insert_bundle(text.big_bundle(""))
                 .id();
-            let color = if (x + y) % 2 == 0 {
-                Color::MAROON
-            } else {
-                Color::BLACK
-            };
-            let square = commands
-                .spawn_bundle(ButtonBundle {
+            let square = board.squares[index];
+            commands
+                .entity(square)
+                .insert_bundle(ButtonBundle {
                     style: Style {
                         size: Size::new(Val::Px(TILE_SIZE), Val::Px(TILE_SIZE)),
                         margin: Rect::all(Val::Px(5.0)),
@@ -392,12 +1047,11 @@ fn make_board(
                         justify_content: JustifyContent::Center,
                         ..Default::default()
                     },
-                    color: UiColor(color),
+                    color: UiColor(square_color(index)),
                     ..Default::default()
                 })
                 .insert(ButtonCommand::Grid(index))
-                .push_children(&[contents])
-                .id();
+                .push_children(&[contents]);
             out.push(square);
         }
         commands.entity(row).push_children(&out);
@@ -406,10 +1060,18 @@ fn make_board(
     commands.entity(s).push_children(&[title, grid]);
 }
 
-fn make_game_over(mut commands: Commands, ui_nodes: Res<UiNodes>, text: Res<GameText>) {
-    let text_bundle = text.bundle("game over");
-    let game_over_message = commands
-        .spawn_bundle(TextBundle {
+fn make_game_over(
+    mut commands: Commands,
+    ui_nodes: Res<UiNodes>,
+    text: Res<GameText>,
+    game_over_ui: Res<GameOverUi>,
+    language: Res<Language>,
+) {
+    let text_bundle = text.bundle(language.localized(TextKey::GameOver));
+    let game_over_message = game_over_ui.message;
+    commands
+        .entity(game_over_message)
+        .insert_bundle(TextBundle {
             style: Style {
                 margin: Rect {
                     bottom: Val::Px(20.0),
@@ -418,14 +1080,16 @@ fn make_game_over(mut commands: Commands, ui_nodes: Res<UiNodes>, text: Res<Game
                 ..text_bundle.style
             },
             ..text_bundle
-        })
-        .id();
+        });
     let [play_button, quit_button] = [
-        ("play again", ButtonCommand::Play),
-        ("quit", ButtonCommand::Quit),
+        (TextKey::PlayAgain, ButtonCommand::Play),
+        (TextKey::Quit, ButtonCommand::Quit),
     ]
-    .map(|(label, button_command)| {
-        let button_label = commands.spawn_bundle(text.bundle(label)).id();
+    .map(|(key, button_command)| {
+        let button_label = commands
+            .spawn_bundle(text.bundle(language.localized(key)))
+            .insert(Localized(key))
+            .id();
         commands
             .spawn_bundle(ButtonBundle {
                 style: Style {
@@ -450,34 +1114,154 @@ fn make_game_over(mut commands: Commands, ui_nodes: Res<UiNodes>, text: Res<Game
     ]);
 }
 
+fn spawn_digit(commands: &mut Commands, segments: [Entity; 7]) -> Entity {
+    for (i, &e) in segments.iter().enumerate() {
+        commands.entity(e).insert_bundle(NodeBundle {
+            color: UiColor(SEGMENT_OFF),
+            style: segment_style(i),
+            ..Default::default()
+        });
+    }
+    commands
+        .spawn_bundle(NodeBundle {
+            color: UiColor(Color::NONE),
+            style: Style {
+                size: Size {
+                    width: Val::Px(DIGIT_WIDTH),
+                    height: Val::Px(DIGIT_HEIGHT),
+                },
+                margin: Rect::all(Val::Px(4.0)),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .push_children(&segments)
+        .id()
+}
+
+fn spawn_scoreboard(
+    commands: &mut Commands,
+    text: &GameText,
+    language: &Language,
+    display: &ScoreDisplay,
+) -> Entity {
+    let groups = [
+        (TextKey::You, display.player),
+        (TextKey::Cpu, display.computer),
+        (TextKey::Draws, display.draws),
+    ]
+    .map(|(key, segments)| {
+        let label_entity = commands
+            .spawn_bundle(text.bundle(language.localized(key)))
+            .insert(Localized(key))
+            .id();
+        let digit = spawn_digit(commands, segments);
+        commands
+            .spawn_bundle(NodeBundle {
+                color: UiColor(Color::NONE),
+                style: Style {
+                    flex_direction: FlexDirection::ColumnReverse,
+                    align_items: AlignItems::Center,
+                    margin: Rect::all(Val::Px(10.0)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .push_children(&[digit, label_entity])
+            .id()
+    });
+    commands
+        .spawn_bundle(NodeBundle {
+            color: UiColor(Color::NONE),
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                margin: Rect::all(Val::Px(10.0)),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .push_children(&groups)
+        .id()
+}
+
+fn make_scoreboard(
+    mut commands: Commands,
+    ui_nodes: Res<UiNodes>,
+    text: Res<GameText>,
+    score_ui: Res<ScoreUi>,
+    language: Res<Language>,
+) {
+    let board_scoreboard = spawn_scoreboard(&mut commands, &text, &language, &score_ui.board);
+    commands
+        .entity(ui_nodes.board())
+        .push_children(&[board_scoreboard]);
+
+    let game_over_scoreboard =
+        spawn_scoreboard(&mut commands, &text, &language, &score_ui.game_over);
+    commands
+        .entity(ui_nodes.game_over())
+        .push_children(&[game_over_scoreboard]);
+}
+
 fn update_display(
     game_state: Res<State<GameState>>,
+    is_paused: Res<IsPaused>,
     ui_nodes: Res<UiNodes>,
     mut query: Query<&mut Style>,
 ) {
     match game_state.current() {
-        GameState::Menu => [true, false, false],
-        GameState::Playing => [false, true, false],
-        GameState::GameOver => [false, true, true],
+        GameState::Menu => [true, false, false, false],
+        GameState::Playing => [false, true, false, false],
+        GameState::GameOver => [false, true, true, false],
+        GameState::Settings => [false, false, false, true],
     }
     .into_iter()
     .zip(ui_nodes.states)
     .for_each(|(d, entity)| {
         query.get_mut(entity).unwrap().display = if d { Display::Flex } else { Display::None }
     });
+    query.get_mut(ui_nodes.pause).unwrap().display =
+        if *game_state.current() == GameState::Playing && is_paused.0 {
+            Display::Flex
+        } else {
+            Display::None
+        };
+}
+
+struct MovePlayed {
+    index: usize,
+}
+
+struct GameEnded {
+    result: Outcome,
 }
 
 #[derive(Component)]
 enum ButtonCommand {
     Play,
+    Settings,
+    Back,
     Quit,
     Grid(usize),
+    CycleDifficulty,
+    CycleFirstMove,
+    CycleMatchLength,
+    CycleLanguage,
+    Resume,
+    QuitToMenu,
 }
 
 fn button_system(
     mut event: EventWriter<AppExit>,
+    mut move_events: EventWriter<MovePlayed>,
     mut state: ResMut<State<GameState>>,
     mut board: ResMut<Board>,
+    mut settings: ResMut<Settings>,
+    settings_ui: Res<SettingsUi>,
+    mut language: ResMut<Language>,
+    mut is_paused: ResMut<IsPaused>,
     mut interaction_query: Query<
         (&Interaction, &mut UiColor, &ButtonCommand),
         (Changed<Interaction>, With<Button>),
@@ -492,20 +1276,44 @@ fn button_system(
                         board.clear();
                         state.set(GameState::Playing).unwrap();
                     }
+                    ButtonCommand::Settings => {
+                        state.set(GameState::Settings).unwrap();
+                    }
+                    ButtonCommand::Back => {
+                        state.set(GameState::Menu).unwrap();
+                    }
                     ButtonCommand::Quit => event.send(AppExit),
+                    ButtonCommand::CycleDifficulty => {
+                        settings.difficulty = settings.difficulty.next();
+                        text.get_mut(settings_ui.difficulty_label).unwrap().sections[0].value =
+                            settings.difficulty.label(&language);
+                    }
+                    ButtonCommand::CycleFirstMove => {
+                        settings.first_move = settings.first_move.next();
+                        text.get_mut(settings_ui.first_move_label).unwrap().sections[0].value =
+                            settings.first_move.label(&language);
+                    }
+                    ButtonCommand::CycleMatchLength => {
+                        settings.next_match_length();
+                        text.get_mut(settings_ui.match_length_label)
+                            .unwrap()
+                            .sections[0]
+                            .value = settings.match_length_label(&language);
+                    }
+                    ButtonCommand::CycleLanguage => {
+                        *language = language.next();
+                        text.get_mut(settings_ui.language_label).unwrap().sections[0].value =
+                            language.label();
+                    }
+                    ButtonCommand::Resume => {
+                        is_paused.0 = false;
+                    }
+                    ButtonCommand::QuitToMenu => {
+                        is_paused.0 = false;
+                        state.set(GameState::Menu).unwrap();
+                    }
                     ButtonCommand::Grid(index) => {
-                        if *state.current() == GameState::Playing
-                            && matches!(board.tiles[*index], Tile::Empty)
-                        {
-                            if board.play_move(*index) {
-                                state.set(GameState::GameOver).unwrap();
-                            }
-                            for i in 0..9 {
-                                let e = board.entities[i];
-                                let mut label = text.get_mut(e).unwrap();
-                                label.sections[0].value = board.tiles[i].piece().to_owned();
-                            }
-                        }
+                        move_events.send(MovePlayed { index: *index });
                     }
                 }
                 PRESSED_BUTTON.into()
@@ -516,12 +1324,201 @@ fn button_system(
     }
 }
 
-fn clear_grid(board: Res<Board>, mut text: Query<&mut Text>) {
-    for &e in board.entities.iter() {
-        text.get_mut(e).unwrap().sections[0].value = "".to_string();
+// Applies a queued `MovePlayed`, lets the AI reply, and fires `GameEnded` when
+// `play_move` reports the round is over. `sync_board_text` picks up the
+// resulting board change to redraw the grid, keeping this system free of any
+// rendering concerns.
+fn resolve_move(
+    mut move_events: EventReader<MovePlayed>,
+    mut game_ended_events: EventWriter<GameEnded>,
+    mut board: ResMut<Board>,
+    settings: Res<Settings>,
+    state: Res<State<GameState>>,
+    is_paused: Res<IsPaused>,
+) {
+    for MovePlayed { index } in move_events.iter() {
+        if *state.current() != GameState::Playing
+            || is_paused.0
+            || !matches!(board.tiles[*index], Tile::Empty)
+        {
+            continue;
+        }
+        let outcome = board.play_move(*index, settings.difficulty);
+        if !matches!(outcome, Outcome::Ongoing) {
+            game_ended_events.send(GameEnded { result: outcome });
+        }
+    }
+}
+
+// Subscribes to `GameEnded` rather than reacting inline in `resolve_move`, so
+// other systems (move animation, sound) can hook the same event later without
+// touching board logic.
+fn apply_game_ended(
+    mut commands: Commands,
+    mut game_ended_events: EventReader<GameEnded>,
+    mut state: ResMut<State<GameState>>,
+    language: Res<Language>,
+    mut score: ResMut<Score>,
+    mut winning_line: ResMut<WinningLine>,
+    game_over_ui: Res<GameOverUi>,
+    mut text: Query<&mut Text>,
+) {
+    for GameEnded { result } in game_ended_events.iter() {
+        let message_key = match *result {
+            Outcome::Win(winner, line) => {
+                winning_line.0 = Some(line);
+                let key = match winner {
+                    Tile::O => {
+                        score.player = (score.player + 1).min(MAX_TALLY);
+                        TextKey::PlayerWins
+                    }
+                    Tile::X => {
+                        score.computer = (score.computer + 1).min(MAX_TALLY);
+                        TextKey::ComputerWins
+                    }
+                    Tile::Empty => unreachable!("winner_of never reports Tile::Empty as a winner"),
+                };
+                key
+            }
+            Outcome::Draw => {
+                score.draws = (score.draws + 1).min(MAX_TALLY);
+                TextKey::Draw
+            }
+            Outcome::Ongoing => continue,
+        };
+        text.get_mut(game_over_ui.message).unwrap().sections[0].value =
+            language.localized(message_key).to_owned();
+        commands
+            .entity(game_over_ui.message)
+            .insert(Localized(message_key));
+        state.set(GameState::GameOver).unwrap();
+    }
+}
+
+// Redraws the nine tile labels whenever `Board` changes, whether that change
+// came from `resolve_move`, `start_playing`'s seeded AI move, or `Play`
+// clearing the grid.
+fn sync_board_text(board: Res<Board>, mut text: Query<&mut Text>) {
+    if !board.is_changed() {
+        return;
+    }
+    for index in 0..9 {
+        text.get_mut(board.entities[index]).unwrap().sections[0].value =
+            board.tiles[index].piece().to_owned();
     }
 }
 
+// Starts a fresh game, seeding an AI move if the computer moves first. If the
+// previous game clinched the match (a side reached `settings.match_length`),
+// the scoreboard is reset here rather than in `apply_game_ended`, so the
+// GameOver screen the player just came from still showed the score that won
+// the match.
+fn start_playing(
+    mut board: ResMut<Board>,
+    settings: Res<Settings>,
+    mut is_paused: ResMut<IsPaused>,
+    mut winning_line: ResMut<WinningLine>,
+    mut colors: Query<&mut UiColor>,
+    mut score: ResMut<Score>,
+) {
+    is_paused.0 = false;
+    winning_line.0 = None;
+    if score.player >= settings.match_length || score.computer >= settings.match_length {
+        *score = Score::default();
+    }
+    for index in 0..9 {
+        colors.get_mut(board.squares[index]).unwrap().0 = square_color(index);
+    }
+    if settings.first_move == FirstMove::Computer {
+        let index = board.choose_ai_move(settings.difficulty);
+        board.tiles[index] = Tile::X;
+        board.moves += 1;
+    }
+}
+
+fn highlight_winning_line(
+    winning_line: Res<WinningLine>,
+    board: Res<Board>,
+    mut colors: Query<&mut UiColor>,
+) {
+    if !winning_line.is_changed() {
+        return;
+    }
+    if let Some(line) = winning_line.0 {
+        for index in line {
+            colors.get_mut(board.squares[index]).unwrap().0 = WIN_HIGHLIGHT;
+        }
+    }
+}
+
+fn toggle_pause(
+    keyboard: Res<Input<KeyCode>>,
+    state: Res<State<GameState>>,
+    mut is_paused: ResMut<IsPaused>,
+) {
+    if *state.current() == GameState::Playing && keyboard.just_pressed(KeyCode::Escape) {
+        is_paused.0 = !is_paused.0;
+    }
+}
+
+fn set_digit(colors: &mut Query<&mut UiColor>, segments: [Entity; 7], value: u32) {
+    let pattern = DIGIT_SEGMENTS[(value % 10) as usize];
+    for (i, &e) in segments.iter().enumerate() {
+        colors.get_mut(e).unwrap().0 = if pattern[i] { SEGMENT_ON } else { SEGMENT_OFF };
+    }
+}
+
+fn update_score_display(
+    score: Res<Score>,
+    score_ui: Res<ScoreUi>,
+    mut colors: Query<&mut UiColor>,
+) {
+    if !score.is_changed() {
+        return;
+    }
+    for display in [&score_ui.board, &score_ui.game_over] {
+        set_digit(&mut colors, display.player, score.player);
+        set_digit(&mut colors, display.computer, score.computer);
+        set_digit(&mut colors, display.draws, score.draws);
+    }
+}
+
+fn clear_score(mut score: ResMut<Score>) {
+    *score = Score::default();
+}
+
+fn retranslate(language: Res<Language>, mut text: Query<(&Localized, &mut Text)>) {
+    if !language.is_changed() {
+        return;
+    }
+    for (Localized(key), mut text) in text.iter_mut() {
+        text.sections[0].value = language.localized(*key).to_owned();
+    }
+}
+
+// The settings row labels are parameterized (the selected variant, the match
+// length number), so unlike `Localized` text they can't be rewritten from the
+// `TextKey` alone; rebuild them from `Settings` whenever the language changes.
+fn retranslate_settings(
+    language: Res<Language>,
+    settings: Res<Settings>,
+    settings_ui: Res<SettingsUi>,
+    mut text: Query<&mut Text>,
+) {
+    if !language.is_changed() {
+        return;
+    }
+    text.get_mut(settings_ui.difficulty_label).unwrap().sections[0].value =
+        settings.difficulty.label(&language);
+    text.get_mut(settings_ui.first_move_label).unwrap().sections[0].value =
+        settings.first_move.label(&language);
+    text.get_mut(settings_ui.match_length_label)
+        .unwrap()
+        .sections[0]
+        .value = settings.match_length_label(&language);
+    text.get_mut(settings_ui.language_label).unwrap().sections[0].value = language.label();
+}
+
 fn main() {
     App::new()
         .insert_resource(ClearColor(Color::BLACK))
@@ -530,16 +1527,126 @@ fn main() {
         .init_resource::<GameText>()
         .init_resource::<UiNodes>()
         .init_resource::<Board>()
+        .init_resource::<Settings>()
+        .init_resource::<SettingsUi>()
+        .init_resource::<Language>()
+        .init_resource::<IsPaused>()
+        .init_resource::<GameOverUi>()
+        .init_resource::<WinningLine>()
+        .init_resource::<Score>()
+        .init_resource::<ScoreUi>()
+        .add_event::<MovePlayed>()
+        .add_event::<GameEnded>()
         .add_startup_system_set(
             SystemSet::new()
-            .with_system(setup)
-            .with_system(make_ui_root)
-            .with_system(make_menu)
-            .with_system(make_board)
-            .with_system(make_game_over),
+                .with_system(setup)
+                .with_system(make_ui_root)
+                .with_system(make_menu)
+                .with_system(make_board)
+                .with_system(make_game_over)
+                .with_system(make_settings)
+                .with_system(make_pause_overlay)
+                .with_system(make_scoreboard),
         )
-        .add_system_set(SystemSet::on_enter(GameState::Playing).with_system(clear_grid))
+        .add_system_set(SystemSet::on_enter(GameState::Playing).with_system(start_playing))
+        .add_system_set(SystemSet::on_enter(GameState::Menu).with_system(clear_score))
         .add_system(update_display)
         .add_system(button_system)
+        .add_system(resolve_move)
+        .add_system(apply_game_ended)
+        .add_system(sync_board_text)
+        .add_system(toggle_pause)
+        .add_system(highlight_winning_line)
+        .add_system(update_score_display)
+        .add_system(retranslate)
+        .add_system(retranslate_settings)
         .run();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::event::Events;
+
+    fn empty_board() -> Board {
+        Board::from_world(&mut World::new())
+    }
+
+    #[test]
+    fn winner_of_detects_a_completed_line() {
+        let mut tiles = [Tile::Empty; 9];
+        tiles[0] = Tile::X;
+        tiles[1] = Tile::X;
+        tiles[2] = Tile::X;
+        assert!(winner_of(&tiles) == Some((Tile::X, [0, 1, 2])));
+    }
+
+    #[test]
+    fn winner_of_is_none_without_a_completed_line() {
+        assert!(winner_of(&[Tile::Empty; 9]) == None);
+    }
+
+    #[test]
+    fn best_move_takes_an_immediate_win() {
+        let mut board = empty_board();
+        #[rustfmt::skip]
+        let tiles = [
+            Tile::X,     Tile::X,     Tile::Empty,
+            Tile::O,     Tile::O,     Tile::Empty,
+            Tile::Empty, Tile::Empty, Tile::Empty,
+        ];
+        board.tiles = tiles;
+        assert_eq!(board.best_move(), 2);
+    }
+
+    #[test]
+    fn best_move_blocks_an_immediate_loss() {
+        let mut board = empty_board();
+        #[rustfmt::skip]
+        let tiles = [
+            Tile::O,     Tile::X,     Tile::Empty,
+            Tile::O,     Tile::Empty, Tile::Empty,
+            Tile::Empty, Tile::Empty, Tile::Empty,
+        ];
+        board.tiles = tiles;
+        assert_eq!(board.best_move(), 6);
+    }
+
+    #[test]
+    fn resolve_move_ends_the_game_on_a_winning_move() {
+        let mut app = App::new();
+        app.add_state(GameState::Playing)
+            .insert_resource(IsPaused(false))
+            .insert_resource(Settings {
+                difficulty: Difficulty::Hard,
+                first_move: FirstMove::Player,
+                match_length: 3,
+            })
+            .insert_resource(empty_board())
+            .add_event::<MovePlayed>()
+            .add_event::<GameEnded>()
+            .add_system(resolve_move);
+
+        {
+            let mut board = app.world.resource_mut::<Board>();
+            #[rustfmt::skip]
+            let tiles = [
+                Tile::O,     Tile::O,     Tile::Empty,
+                Tile::X,     Tile::Empty, Tile::Empty,
+                Tile::Empty, Tile::Empty, Tile::Empty,
+            ];
+            board.tiles = tiles;
+            board.moves = 3;
+        }
+
+        app.world
+            .resource_mut::<Events<MovePlayed>>()
+            .send(MovePlayed { index: 2 });
+        app.update();
+
+        let events = app.world.resource::<Events<GameEnded>>();
+        let sent: Vec<_> = events.get_reader().iter(events).collect();
+        assert_eq!(sent.len(), 1);
+        assert!(matches!(sent[0].result, Outcome::Win(Tile::O, _)));
+    }
+}